@@ -1,29 +1,208 @@
 use paste::paste;
 use std::{
+    cmp::Ordering,
     fmt::Display,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
     str::FromStr,
 };
 
-const KB: u32 = 10;
-const MB: u32 = 20;
-const GB: u32 = 30;
-const TB: u32 = 40;
+// IEC (binary, 1024-based) multipliers.
+const KIB: i128 = 1 << 10;
+const MIB: i128 = 1 << 20;
+const GIB: i128 = 1 << 30;
+const TIB: i128 = 1 << 40;
+const PIB: i128 = 1 << 50;
+const EIB: i128 = 1 << 60;
+
+// SI (decimal, 1000-based) multipliers.
+const KB: i128 = 1_000;
+const MB: i128 = 1_000_000;
+const GB: i128 = 1_000_000_000;
+const TB: i128 = 1_000_000_000_000;
+const PB: i128 = 1_000_000_000_000_000;
+const EB: i128 = 1_000_000_000_000_000_000;
 
 fn err_message(s: &str) -> String {
-    format!("invalid format for unit size: {s}. Acceptable formats are nB, nKB, nMB,nGB, nTB")
+    format!(
+        "invalid format for unit size: {s}. Acceptable formats are nB, nKB, nMB, nGB, nTB, nPB, nEB (SI) or nKiB, nMiB, nGiB, nTiB, nPiB, nEiB (IEC)"
+    )
 }
 
-const fn down_from(lhs: f32, dec: u32) -> f32 {
-    lhs * (2_u64.pow(dec) as f32)
+/// Selects which scale `Unit::to_string_as` renders a value in: binary
+/// (IEC, powers of 1024) or decimal (SI, powers of 1000).
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum UnitSystem {
+    #[default]
+    Iec,
+    Si,
 }
 
-const fn up_to(lhs: f32, dec: u32) -> f32 {
-    lhs / (2_u64.pow(dec) as f32)
+/// The exact suffix a `Unit` was parsed with, or inferred as. Kept around so
+/// `Display`/`Serialize` can reproduce it verbatim instead of always
+/// re-picking a scale from the byte count.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum SourceUnit {
+    B,
+    KiB,
+    MiB,
+    GiB,
+    TiB,
+    PiB,
+    EiB,
+    KB,
+    MB,
+    GB,
+    TB,
+    PB,
+    EB,
 }
 
-#[derive(PartialEq, PartialOrd, Debug, Clone, Copy, Default)]
-pub struct Unit(f32);
+impl SourceUnit {
+    const fn multiplier(self) -> i128 {
+        match self {
+            Self::B => 1,
+            Self::KiB => KIB,
+            Self::MiB => MIB,
+            Self::GiB => GIB,
+            Self::TiB => TIB,
+            Self::PiB => PIB,
+            Self::EiB => EIB,
+            Self::KB => KB,
+            Self::MB => MB,
+            Self::GB => GB,
+            Self::TB => TB,
+            Self::PB => PB,
+            Self::EB => EB,
+        }
+    }
+
+    const fn suffix(self) -> &'static str {
+        match self {
+            Self::B => "B",
+            Self::KiB => "KiB",
+            Self::MiB => "MiB",
+            Self::GiB => "GiB",
+            Self::TiB => "TiB",
+            Self::PiB => "PiB",
+            Self::EiB => "EiB",
+            Self::KB => "kB",
+            Self::MB => "MB",
+            Self::GB => "GB",
+            Self::TB => "TB",
+            Self::PB => "PB",
+            Self::EB => "EB",
+        }
+    }
+
+    fn parse(suffix: &str) -> Option<Self> {
+        Some(match suffix {
+            "b" => Self::B,
+            "kib" => Self::KiB,
+            "mib" => Self::MiB,
+            "gib" => Self::GiB,
+            "tib" => Self::TiB,
+            "pib" => Self::PiB,
+            "eib" => Self::EiB,
+            "kb" => Self::KB,
+            "mb" => Self::MB,
+            "gb" => Self::GB,
+            "tb" => Self::TB,
+            "pb" => Self::PB,
+            "eb" => Self::EB,
+            _ => return None,
+        })
+    }
+
+    /// Picks the largest IEC unit whose multiplier still fits the magnitude.
+    fn infer_iec(bytes: i128) -> Self {
+        let magnitude = bytes.unsigned_abs();
+        if magnitude >= Self::EiB.multiplier() as u128 {
+            return Self::EiB;
+        }
+        if magnitude >= Self::PiB.multiplier() as u128 {
+            return Self::PiB;
+        }
+        if magnitude >= Self::TiB.multiplier() as u128 {
+            return Self::TiB;
+        }
+        if magnitude >= Self::GiB.multiplier() as u128 {
+            return Self::GiB;
+        }
+        if magnitude >= Self::MiB.multiplier() as u128 {
+            return Self::MiB;
+        }
+        if magnitude >= Self::KiB.multiplier() as u128 {
+            return Self::KiB;
+        }
+        Self::B
+    }
+
+    /// Picks the largest SI unit whose multiplier still fits the magnitude.
+    fn infer_si(bytes: i128) -> Self {
+        let magnitude = bytes.unsigned_abs();
+        if magnitude >= Self::EB.multiplier() as u128 {
+            return Self::EB;
+        }
+        if magnitude >= Self::PB.multiplier() as u128 {
+            return Self::PB;
+        }
+        if magnitude >= Self::TB.multiplier() as u128 {
+            return Self::TB;
+        }
+        if magnitude >= Self::GB.multiplier() as u128 {
+            return Self::GB;
+        }
+        if magnitude >= Self::MB.multiplier() as u128 {
+            return Self::MB;
+        }
+        if magnitude >= Self::KB.multiplier() as u128 {
+            return Self::KB;
+        }
+        Self::B
+    }
+}
+
+fn format_as(bytes: i128, unit: SourceUnit) -> String {
+    if unit == SourceUnit::B {
+        return format!("{bytes}B");
+    }
+    format!("{:.2}{}", bytes as f64 / unit.multiplier() as f64, unit.suffix())
+}
+
+/// A byte count stored as whole bytes, so large sizes stay exact instead of
+/// losing precision the way a floating-point representation does.
+/// [`FromStr`] rounds fractional inputs such as `"1.5KB"` to the nearest
+/// byte, and [`Display`] prints units above bytes with two decimals, so
+/// parsing the displayed text back can give a different byte count.
+///
+/// If this value was produced by [`FromStr`], `Display`/`Serialize` reproduce
+/// the exact unit it was parsed with; otherwise they pick whichever IEC unit
+/// best fits the magnitude, same as [`Unit::infer`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Unit {
+    bytes: i128,
+    unit: Option<SourceUnit>,
+}
+
+impl PartialEq for Unit {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for Unit {}
+
+impl PartialOrd for Unit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Unit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes.cmp(&other.bytes)
+    }
+}
 
 macro_rules! impl_op {
     ($trait:ident, $fname:ident, $op:tt) => {
@@ -31,7 +210,10 @@ macro_rules! impl_op {
             type Output = Self;
 
             fn $fname(self, rhs: Self) -> Self::Output {
-                Self(self.0 $op rhs.0)
+                Self {
+                    bytes: self.bytes $op rhs.bytes,
+                    unit: None,
+                }
             }
         }
 
@@ -39,14 +221,18 @@ macro_rules! impl_op {
             type Output = Unit;
 
             fn $fname(self, rhs: Self) -> Self::Output {
-                Unit(self.0 $op rhs.0)
+                Unit {
+                    bytes: self.bytes $op rhs.bytes,
+                    unit: None,
+                }
             }
         }
 
         paste! {
             impl [<$trait Assign>] for Unit {
                 fn [<$fname _assign>](&mut self, rhs: Self) {
-                    self.0 = self.0 $op rhs.0;
+                    self.bytes = self.bytes $op rhs.bytes;
+                    self.unit = None;
                 }
             }
         }
@@ -59,28 +245,130 @@ impl_op!(Mul, mul, *);
 impl_op!(Div, div, /);
 
 impl Unit {
-    pub const fn as_bytes(&self) -> f32 {
-        self.0
+    /// Checked addition. Returns `None` on overflow, same as [`i128::checked_add`].
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.bytes.checked_add(rhs.bytes).map(Self::from_bytes)
+    }
+
+    /// Checked subtraction. Returns `None` on overflow, same as [`i128::checked_sub`].
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.bytes.checked_sub(rhs.bytes).map(Self::from_bytes)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow, same as [`i128::checked_mul`].
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.bytes.checked_mul(rhs.bytes).map(Self::from_bytes)
+    }
+
+    /// Checked division. Returns `None` on overflow or when `rhs` is zero,
+    /// same as [`i128::checked_div`].
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.bytes.checked_div(rhs.bytes).map(Self::from_bytes)
+    }
+
+    /// Saturating addition, clamped to `[0, i128::MAX]`. Recommended over
+    /// `+` for untrusted arithmetic, where silent overflow would be a bug.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::from_bytes(self.bytes.saturating_add(rhs.bytes).max(0))
+    }
+
+    /// Saturating subtraction, clamped to `[0, i128::MAX]`.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::from_bytes(self.bytes.saturating_sub(rhs.bytes).max(0))
+    }
+
+    /// Saturating multiplication, clamped to `[0, i128::MAX]`.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Self::from_bytes(self.bytes.saturating_mul(rhs.bytes).max(0))
+    }
+
+    /// Saturating division, clamped to `[0, i128::MAX]`. Dividing by zero
+    /// saturates toward the sign of `self` instead of panicking.
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        if rhs.bytes == 0 {
+            let saturated = if self.bytes < 0 { 0 } else { i128::MAX };
+            return Self::from_bytes(saturated);
+        }
+        Self::from_bytes(self.bytes.saturating_div(rhs.bytes).max(0))
+    }
+}
+
+impl Unit {
+    pub const fn as_bytes(&self) -> i128 {
+        self.bytes
+    }
+
+    pub const fn from_bytes(value: i128) -> Self {
+        Self {
+            bytes: value,
+            unit: None,
+        }
+    }
+
+    pub const fn from_kibi_bytes(value: i128) -> Self {
+        Self::from_bytes(value * KIB)
+    }
+
+    pub const fn from_mebi_bytes(value: i128) -> Self {
+        Self::from_bytes(value * MIB)
+    }
+
+    pub const fn from_gibi_bytes(value: i128) -> Self {
+        Self::from_bytes(value * GIB)
+    }
+
+    pub const fn from_tebi_bytes(value: i128) -> Self {
+        Self::from_bytes(value * TIB)
+    }
+
+    pub const fn from_pebi_bytes(value: i128) -> Self {
+        Self::from_bytes(value * PIB)
     }
 
-    pub const fn from_bytes(value: f32) -> Self {
-        Self(value)
+    pub const fn from_exbi_bytes(value: i128) -> Self {
+        Self::from_bytes(value * EIB)
     }
 
-    pub const fn from_kilo_bytes(value: f32) -> Self {
-        Self(down_from(value, KB))
+    pub const fn from_kilo_bytes(value: i128) -> Self {
+        Self::from_bytes(value * KB)
     }
 
-    pub const fn from_mega_bytes(value: f32) -> Self {
-        Self(down_from(value, MB))
+    pub const fn from_mega_bytes(value: i128) -> Self {
+        Self::from_bytes(value * MB)
     }
 
-    pub const fn from_giga_bytes(value: f32) -> Self {
-        Self(down_from(value, GB))
+    pub const fn from_giga_bytes(value: i128) -> Self {
+        Self::from_bytes(value * GB)
     }
 
-    pub const fn from_tera_bytes(value: f32) -> Self {
-        Self(down_from(value, TB))
+    pub const fn from_tera_bytes(value: i128) -> Self {
+        Self::from_bytes(value * TB)
+    }
+
+    pub const fn from_peta_bytes(value: i128) -> Self {
+        Self::from_bytes(value * PB)
+    }
+
+    pub const fn from_exa_bytes(value: i128) -> Self {
+        Self::from_bytes(value * EB)
+    }
+
+    /// Renders this size using the given [`UnitSystem`], ignoring any unit
+    /// captured by `FromStr` and always picking the best fit for that scale.
+    pub fn to_string_as(&self, system: UnitSystem) -> String {
+        match system {
+            UnitSystem::Iec => format_as(self.bytes, SourceUnit::infer_iec(self.bytes)),
+            UnitSystem::Si => format_as(self.bytes, SourceUnit::infer_si(self.bytes)),
+        }
+    }
+
+    /// Returns a copy of this size tagged with whichever IEC unit best fits
+    /// its magnitude, discarding any unit captured by `FromStr`.
+    pub fn infer(&self) -> Self {
+        Self {
+            bytes: self.bytes,
+            unit: Some(SourceUnit::infer_iec(self.bytes)),
+        }
     }
 }
 
@@ -95,52 +383,36 @@ impl FromStr for Unit {
         let value = value
             .trim()
             .replace(",", ".")
-            .parse::<f32>()
+            .parse::<f64>()
             .map_err(|err| format!("{}: {}", err_message(s), err))?;
-        let unit = unit.trim();
-        Ok(match unit {
-            "B" => Self::from_bytes(value),
-            "KB" => Self::from_kilo_bytes(value),
-            "MB" => Self::from_mega_bytes(value),
-            "GB" => Self::from_giga_bytes(value),
-            "TB" => Self::from_tera_bytes(value),
-            _ => return Err(err_message(s)),
+        // Fractional inputs like "1.5GB" only make sense while scaling, so
+        // the rounding happens here rather than in the integer constructors.
+        let unit = SourceUnit::parse(&unit.trim().to_ascii_lowercase())
+            .ok_or_else(|| err_message(s))?;
+        let bytes = (value * unit.multiplier() as f64).round() as i128;
+        Ok(Self {
+            bytes,
+            unit: Some(unit),
         })
     }
 }
 
 impl From<u64> for Unit {
     fn from(value: u64) -> Self {
-        Self(value as f32)
+        Self::from_bytes(value as i128)
     }
 }
 
 impl From<f32> for Unit {
     fn from(value: f32) -> Self {
-        Self(value)
+        Self::from_bytes(value.round() as i128)
     }
 }
 
 impl Display for Unit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let value = if self.0.is_sign_negative() {
-            self.0 * -1_f32
-        } else {
-            self.0
-        };
-        if value >= down_from(1_f32, TB) {
-            return write!(f, "{:.2}TB", up_to(self.0, TB));
-        }
-        if value >= down_from(1_f32, GB) {
-            return write!(f, "{:.2}GB", up_to(self.0, GB));
-        }
-        if value >= down_from(1_f32, MB) {
-            return write!(f, "{:.2}MB", up_to(self.0, MB));
-        }
-        if value >= down_from(1_f32, KB) {
-            return write!(f, "{:.2}KB", up_to(self.0, KB));
-        }
-        write!(f, "{}B", self.0)
+        let unit = self.unit.unwrap_or_else(|| SourceUnit::infer_iec(self.bytes));
+        write!(f, "{}", format_as(self.bytes, unit))
     }
 }
 
@@ -167,7 +439,7 @@ pub mod serde {
         type Value = Unit;
 
         fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-            formatter.write_str("string with the format: 99TB|GB|MB|B")
+            formatter.write_str("string with the format: 99TiB|GiB|MiB|KiB|B or 99TB|GB|MB|kB|B")
         }
 
         fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -186,6 +458,174 @@ pub mod serde {
             deserializer.deserialize_str(UnitVisitor)
         }
     }
+
+    /// Numeric (de)serialization: a raw byte count, for machine-to-machine
+    /// configs where the human-readable string form is lossy and awkward.
+    ///
+    /// ```ignore
+    /// #[derive(serde::Serialize, serde::Deserialize)]
+    /// struct Config {
+    ///     #[serde(with = "simple_size::serde::bytes")]
+    ///     limit: Unit,
+    /// }
+    /// ```
+    pub mod bytes {
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        use crate::Unit;
+
+        pub fn serialize<S>(value: &Unit, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            value.as_bytes().serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Unit, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            i128::deserialize(deserializer).map(Unit::from_bytes)
+        }
+    }
+
+    /// Textual (de)serialization, e.g. `"10.00GB"`. Equivalent to the
+    /// default `Serialize`/`Deserialize` impls, exposed as a named module so
+    /// it can be paired explicitly via `#[serde(with = "...")]`.
+    pub mod string {
+        use std::str::FromStr;
+
+        use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+        use crate::Unit;
+
+        pub fn serialize<S>(value: &Unit, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&value.to_string())
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Unit, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            Unit::from_str(&s).map_err(Error::custom)
+        }
+    }
+
+    /// Deserializes either a bare byte count or a unit string like
+    /// `"512MB"`, for configs that allow either form; always serializes as a
+    /// number.
+    pub mod permissive {
+        use std::str::FromStr;
+
+        use serde::{Deserializer, Serializer, de::Error};
+        use serde::de::Visitor;
+
+        use crate::Unit;
+
+        pub fn serialize<S>(value: &Unit, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_i128(value.as_bytes())
+        }
+
+        struct PermissiveVisitor;
+
+        impl<'de> Visitor<'de> for PermissiveVisitor {
+            type Value = Unit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a byte count or a unit string like \"512MB\"")
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Unit::from_bytes(v as i128))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Unit::from_bytes(v as i128))
+            }
+
+            fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Unit::from_bytes(v))
+            }
+
+            fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Unit::from_bytes(v as i128))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Unit::from_str(v).map_err(E::custom)
+            }
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Unit, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(PermissiveVisitor)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct BytesConfig {
+            #[serde(with = "bytes")]
+            limit: Unit,
+        }
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct PermissiveConfig {
+            #[serde(with = "permissive")]
+            limit: Unit,
+        }
+
+        #[test]
+        fn bytes_round_trip() {
+            let config = BytesConfig {
+                limit: Unit::from_giga_bytes(1),
+            };
+            let json = serde_json::to_string(&config).unwrap();
+            assert_eq!(json, r#"{"limit":1000000000}"#);
+            let decoded: BytesConfig = serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded.limit, config.limit);
+        }
+
+        #[test]
+        fn permissive_accepts_number_or_string() {
+            let from_number: PermissiveConfig = serde_json::from_str(r#"{"limit":1024}"#).unwrap();
+            assert_eq!(from_number.limit, Unit::from_bytes(1024));
+
+            let from_string: PermissiveConfig =
+                serde_json::from_str(r#"{"limit":"1KiB"}"#).unwrap();
+            assert_eq!(from_string.limit, Unit::from_bytes(1024));
+
+            let json = serde_json::to_string(&from_string).unwrap();
+            assert_eq!(json, r#"{"limit":1024}"#);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -193,51 +633,142 @@ mod tests {
     use super::*;
 
     #[test]
-    fn from_str() {
-        assert_eq!(Unit(1_f32), Unit::from_str("1B").unwrap());
-        assert_eq!(Unit(1024_f32), Unit::from_str("1KB").unwrap());
-        assert_eq!(Unit(1_048_576_f32), Unit::from_str("1MB").unwrap());
-        assert_eq!(Unit(1_073_741_824_f32), Unit::from_str("1GB").unwrap());
-        assert_eq!(Unit(1_099_511_627_776_f32), Unit::from_str("1TB").unwrap());
+    fn from_str_iec() {
+        assert_eq!(Unit::from_bytes(1), Unit::from_str("1B").unwrap());
+        assert_eq!(Unit::from_bytes(1024), Unit::from_str("1KiB").unwrap());
+        assert_eq!(Unit::from_bytes(1_048_576), Unit::from_str("1MiB").unwrap());
+        assert_eq!(Unit::from_bytes(1_073_741_824), Unit::from_str("1GiB").unwrap());
+        assert_eq!(Unit::from_bytes(1_099_511_627_776), Unit::from_str("1TiB").unwrap());
+    }
+
+    #[test]
+    fn from_str_si() {
+        assert_eq!(Unit::from_bytes(1_000), Unit::from_str("1KB").unwrap());
+        assert_eq!(Unit::from_bytes(1_000_000), Unit::from_str("1MB").unwrap());
+        assert_eq!(Unit::from_bytes(1_000_000_000), Unit::from_str("1GB").unwrap());
+        assert_eq!(Unit::from_bytes(1_000_000_000_000), Unit::from_str("1TB").unwrap());
+    }
+
+    #[test]
+    fn from_str_case_insensitive() {
+        assert_eq!(Unit::from_bytes(1_000), Unit::from_str("1kb").unwrap());
+        assert_eq!(Unit::from_bytes(1024), Unit::from_str("1kib").unwrap());
+        assert_eq!(Unit::from_bytes(1024), Unit::from_str("1 KiB").unwrap());
+    }
+
+    #[test]
+    fn from_str_fractional() {
+        assert_eq!(Unit::from_bytes(1_536), Unit::from_str("1.5KiB").unwrap());
+        assert_eq!(Unit::from_bytes(1_500), Unit::from_str("1.5KB").unwrap());
     }
 
     #[test]
     fn from_str_negative() {
-        assert_eq!(Unit(-1_f32), Unit::from_str("-1B").unwrap());
-        assert_eq!(Unit(-1024_f32), Unit::from_str("-1KB").unwrap());
-        assert_eq!(Unit(-1_048_576_f32), Unit::from_str("-1MB").unwrap());
-        assert_eq!(Unit(-1_073_741_824_f32), Unit::from_str("-1GB").unwrap());
-        assert_eq!(
-            Unit(-1_099_511_627_776_f32),
-            Unit::from_str("-1TB").unwrap()
-        );
+        assert_eq!(Unit::from_bytes(-1), Unit::from_str("-1B").unwrap());
+        assert_eq!(Unit::from_bytes(-1024), Unit::from_str("-1KiB").unwrap());
+        assert_eq!(Unit::from_bytes(-1_048_576), Unit::from_str("-1MiB").unwrap());
+        assert_eq!(Unit::from_bytes(-1_073_741_824), Unit::from_str("-1GiB").unwrap());
+        assert_eq!(Unit::from_bytes(-1_099_511_627_776), Unit::from_str("-1TiB").unwrap());
     }
 
     #[test]
     fn to_string() {
-        let size = Unit::from_str("10TB").unwrap();
-        assert_eq!("10.00TB", size.to_string());
-        let size = Unit::from_str("10GB").unwrap();
-        assert_eq!("10.00GB", size.to_string());
-        let size = Unit::from_str("10MB").unwrap();
-        assert_eq!("10.00MB", size.to_string());
-        let size = Unit::from_str("10KB").unwrap();
-        assert_eq!("10.00KB", size.to_string());
+        let size = Unit::from_str("10TiB").unwrap();
+        assert_eq!("10.00TiB", size.to_string());
+        let size = Unit::from_str("10GiB").unwrap();
+        assert_eq!("10.00GiB", size.to_string());
+        let size = Unit::from_str("10MiB").unwrap();
+        assert_eq!("10.00MiB", size.to_string());
+        let size = Unit::from_str("10KiB").unwrap();
+        assert_eq!("10.00KiB", size.to_string());
         let size = Unit::from_str("10B").unwrap();
         assert_eq!("10B", size.to_string());
     }
 
     #[test]
     fn to_string_negative() {
-        let size = Unit::from_str("-10TB").unwrap();
-        assert_eq!("-10.00TB", size.to_string());
-        let size = Unit::from_str("-10GB").unwrap();
-        assert_eq!("-10.00GB", size.to_string());
-        let size = Unit::from_str("-10MB").unwrap();
-        assert_eq!("-10.00MB", size.to_string());
-        let size = Unit::from_str("-10KB").unwrap();
-        assert_eq!("-10.00KB", size.to_string());
+        let size = Unit::from_str("-10TiB").unwrap();
+        assert_eq!("-10.00TiB", size.to_string());
+        let size = Unit::from_str("-10GiB").unwrap();
+        assert_eq!("-10.00GiB", size.to_string());
+        let size = Unit::from_str("-10MiB").unwrap();
+        assert_eq!("-10.00MiB", size.to_string());
+        let size = Unit::from_str("-10KiB").unwrap();
+        assert_eq!("-10.00KiB", size.to_string());
         let size = Unit::from_str("-10B").unwrap();
         assert_eq!("-10B", size.to_string());
     }
+
+    #[test]
+    fn to_string_as() {
+        let size = Unit::from_kilo_bytes(10);
+        assert_eq!("10.00kB", size.to_string_as(UnitSystem::Si));
+        assert_eq!("9.77KiB", size.to_string_as(UnitSystem::Iec));
+    }
+
+    #[test]
+    fn precision_at_scale() {
+        let size = Unit::from_tera_bytes(1);
+        assert_eq!(1_000_000_000_000, size.as_bytes());
+    }
+
+    #[test]
+    fn round_trip_preserves_parsed_unit() {
+        assert_eq!("512.00MB", Unit::from_str("512MB").unwrap().to_string());
+        assert_eq!("1.50GiB", Unit::from_str("1.50GiB").unwrap().to_string());
+        assert_eq!("10B", Unit::from_str("10B").unwrap().to_string());
+        assert_eq!("2.00kB", Unit::from_str("2kb").unwrap().to_string());
+    }
+
+    #[test]
+    fn infer_drops_parsed_unit() {
+        let size = Unit::from_str("512MB").unwrap();
+        assert_eq!("512.00MB", size.to_string());
+        assert_eq!("488.28MiB", size.infer().to_string());
+    }
+
+    #[test]
+    fn arithmetic_drops_parsed_unit() {
+        let a = Unit::from_str("512MB").unwrap();
+        let b = a + Unit::from_bytes(0);
+        assert_eq!("488.28MiB", b.to_string());
+    }
+
+    #[test]
+    fn equality_ignores_parsed_unit() {
+        assert_eq!(Unit::from_str("1KiB").unwrap(), Unit::from_bytes(1024));
+    }
+
+    #[test]
+    fn checked_ops_report_overflow_and_divide_by_zero() {
+        assert_eq!(
+            Some(Unit::from_bytes(3)),
+            Unit::from_bytes(1).checked_add(Unit::from_bytes(2))
+        );
+        assert_eq!(
+            None,
+            Unit::from_bytes(i128::MAX).checked_add(Unit::from_bytes(1))
+        );
+        assert_eq!(None, Unit::from_bytes(1).checked_div(Unit::from_bytes(0)));
+    }
+
+    #[test]
+    fn saturating_ops_clamp_to_zero_and_max() {
+        assert_eq!(
+            Unit::from_bytes(0),
+            Unit::from_bytes(1).saturating_sub(Unit::from_bytes(2))
+        );
+        assert_eq!(
+            Unit::from_bytes(i128::MAX),
+            Unit::from_bytes(i128::MAX).saturating_add(Unit::from_bytes(1))
+        );
+        assert_eq!(
+            Unit::from_bytes(i128::MAX),
+            Unit::from_bytes(1).saturating_div(Unit::from_bytes(0))
+        );
+        assert_eq!(
+            Unit::from_bytes(0),
+            Unit::from_bytes(-1).saturating_div(Unit::from_bytes(0))
+        );
+    }
 }